@@ -1,3 +1,4 @@
+use std::collections::{BTreeSet, HashMap};
 use std::fmt::{self, Display};
 use std::rc::Rc;
 
@@ -14,23 +15,91 @@ pub enum Expression {
     /// Logical IMPLIES operation with two child `Expression` nodes.
     Implies(Rc<Expression>, Rc<Expression>),
 
+    /// Logical IFF (biconditional, "if and only if") operation with two child `Expression` nodes.
+    Iff(Rc<Expression>, Rc<Expression>),
+
+    /// Logical XOR (exclusive or) operation with two child `Expression` nodes.
+    Xor(Rc<Expression>, Rc<Expression>),
+
     /// Logical NOT operation with a single child `Expression` node.
     Not(Rc<Expression>),
 
     /// Represents a variable in the logical expression, stored as a `String`.
     Var(String),
+
+    /// Universal quantification ("for all"), binding a variable name over a body.
+    ForAll(String, Rc<Expression>),
+
+    /// Existential quantification ("there exists"), binding a variable name over a body.
+    Exists(String, Rc<Expression>),
+
+    /// An n-ary predicate applied to a list of `Term` arguments, e.g. `LOVES(x, y)`.
+    Predicate(String, Vec<Rc<Term>>),
+}
+
+/// A first-order term: a variable, a named constant, or a function applied to other
+/// terms. Used as the arguments of a [`Expression::Predicate`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Term {
+    /// A variable, bound by an enclosing `ForAll`/`Exists` or left free.
+    Var(String),
+
+    /// A named constant individual.
+    Const(String),
+
+    /// An n-ary function applied to other terms, e.g. `f(x, y)`.
+    Func(String, Vec<Rc<Term>>),
+}
+
+impl Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Var(name) | Term::Const(name) => write!(f, "{}", name),
+            Term::Func(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
 }
 
 /// Implementation of the `Display` trait for the `Expression` enum.
 /// This allows for the pretty printing of `Expression` instances in a human-readable format.
+///
+/// The default format fully parenthesizes every binary node. The alternate format
+/// (`{:#}`, or [`Expression::to_pretty_string`]) instead inserts parentheses only where
+/// needed to preserve the expression's structure when re-parsed.
 impl Display for Expression {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.fmt_pretty(f, 0);
+        }
         match self {
             Expression::And(left, right) => write!(f, "({} & {})", left, right),
             Expression::Or(left, right) => write!(f, "({} v {})", left, right),
             Expression::Implies(left, right) => write!(f, "({} -> {})", left, right),
+            Expression::Iff(left, right) => write!(f, "({} <-> {})", left, right),
+            Expression::Xor(left, right) => write!(f, "({} ^ {})", left, right),
             Expression::Not(expr) => write!(f, "~{}", expr),
             Expression::Var(name) => write!(f, "{}", name),
+            Expression::ForAll(name, body) => write!(f, "\u{2200}{}. {}", name, body),
+            Expression::Exists(name, body) => write!(f, "\u{2203}{}. {}", name, body),
+            Expression::Predicate(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
         }
     }
 }
@@ -61,6 +130,36 @@ pub fn not(expr: &Rc<Expression>) -> Rc<Expression> {
     Rc::new(Expression::Not(Rc::clone(expr)))
 }
 
+/// shorthand for universal quantification
+pub fn forall(name: impl Into<String>, body: &Rc<Expression>) -> Rc<Expression> {
+    Rc::new(Expression::ForAll(name.into(), Rc::clone(body)))
+}
+
+/// shorthand for existential quantification
+pub fn exists(name: impl Into<String>, body: &Rc<Expression>) -> Rc<Expression> {
+    Rc::new(Expression::Exists(name.into(), Rc::clone(body)))
+}
+
+/// shorthand for an n-ary predicate applied to terms
+pub fn predicate(name: impl Into<String>, args: Vec<Rc<Term>>) -> Rc<Expression> {
+    Rc::new(Expression::Predicate(name.into(), args))
+}
+
+/// shorthand for a term variable
+pub fn term_var(name: impl Into<String>) -> Rc<Term> {
+    Rc::new(Term::Var(name.into()))
+}
+
+/// shorthand for a term constant
+pub fn term_const(name: impl Into<String>) -> Rc<Term> {
+    Rc::new(Term::Const(name.into()))
+}
+
+/// shorthand for a function application term
+pub fn func(name: impl Into<String>, args: Vec<Rc<Term>>) -> Rc<Term> {
+    Rc::new(Term::Func(name.into(), args))
+}
+
 impl Expression {
     /// Adds an Rc wrapper to the current `Expression` node.
     pub fn wrap(self) -> Rc<Expression> {
@@ -93,7 +192,9 @@ impl Expression {
         match self {
             Expression::And(left, right)
             | Expression::Or(left, right)
-            | Expression::Implies(left, right) => {
+            | Expression::Implies(left, right)
+            | Expression::Iff(left, right)
+            | Expression::Xor(left, right) => {
                 expressions.push(self.clone());
                 expressions.extend(left.list_expressions());
                 expressions.extend(right.list_expressions());
@@ -102,7 +203,11 @@ impl Expression {
                 expressions.push(self.clone());
                 expressions.extend(expr.list_expressions());
             }
-            Expression::Var(_) => expressions.push(self.clone()),
+            Expression::ForAll(_, body) | Expression::Exists(_, body) => {
+                expressions.push(self.clone());
+                expressions.extend(body.list_expressions());
+            }
+            Expression::Var(_) | Expression::Predicate(..) => expressions.push(self.clone()),
         }
         expressions.dedup();
         expressions
@@ -122,4 +227,256 @@ impl Expression {
     pub fn implies(self: &Rc<Self>, other: &Rc<Self>) -> Rc<Self> {
         Rc::new(Self::Implies(Rc::clone(self), Rc::clone(other)))
     }
+
+    /// shorthand for iff (biconditional) expression
+    pub fn iff(self: &Rc<Self>, other: &Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Iff(Rc::clone(self), Rc::clone(other)))
+    }
+
+    /// shorthand for xor expression
+    pub fn xor(self: &Rc<Self>, other: &Rc<Self>) -> Rc<Self> {
+        Rc::new(Self::Xor(Rc::clone(self), Rc::clone(other)))
+    }
+
+    /// Evaluates this expression under the given variable assignment.
+    ///
+    /// Fails with [`EvalError::UndefinedVar`] if a variable appears in the expression
+    /// that has no entry in `assignment`, or with [`EvalError::NotPropositional`] if
+    /// the expression contains a quantifier or predicate, which has no truth value
+    /// under a variable assignment alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use propositional_logic_calculator::expression::var;
+    /// use std::collections::HashMap;
+    ///
+    /// let expr = var("A").and(&var("B"));
+    /// let mut assignment = HashMap::new();
+    /// assignment.insert("A".to_string(), true);
+    /// assignment.insert("B".to_string(), false);
+    /// assert_eq!(expr.evaluate(&assignment).unwrap(), false);
+    /// ```
+    pub fn evaluate(&self, assignment: &HashMap<String, bool>) -> Result<bool, EvalError> {
+        // Non-short-circuiting `&`/`|` so both operands are always checked for errors.
+        match self {
+            Expression::And(left, right) => {
+                Ok(left.evaluate(assignment)? & right.evaluate(assignment)?)
+            }
+            Expression::Or(left, right) => {
+                Ok(left.evaluate(assignment)? | right.evaluate(assignment)?)
+            }
+            Expression::Implies(left, right) => {
+                Ok(!left.evaluate(assignment)? | right.evaluate(assignment)?)
+            }
+            Expression::Iff(left, right) => {
+                Ok(left.evaluate(assignment)? == right.evaluate(assignment)?)
+            }
+            Expression::Xor(left, right) => {
+                Ok(left.evaluate(assignment)? != right.evaluate(assignment)?)
+            }
+            Expression::Not(expr) => Ok(!expr.evaluate(assignment)?),
+            Expression::Var(name) => assignment
+                .get(name)
+                .copied()
+                .ok_or_else(|| EvalError::UndefinedVar(name.clone())),
+            Expression::ForAll(..) | Expression::Exists(..) | Expression::Predicate(..) => {
+                Err(EvalError::NotPropositional)
+            }
+        }
+    }
+
+    /// Collects the set of distinct variable names appearing in this expression.
+    pub fn variables(&self) -> BTreeSet<String> {
+        self.list_expressions()
+            .into_iter()
+            .filter_map(|expr| match expr {
+                Expression::Var(name) => Some(name),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Enumerates every assignment of this expression's variables together with the
+    /// resulting truth value.
+    ///
+    /// Fails with [`EvalError::NotPropositional`] if the expression contains a
+    /// quantifier or predicate, since those have no truth value under a variable
+    /// assignment alone.
+    pub fn truth_table(&self) -> Result<TruthTable, EvalError> {
+        let variables: Vec<String> = self.variables().into_iter().collect();
+        let row_count = 1usize << variables.len();
+        let mut rows = Vec::with_capacity(row_count);
+        for i in 0..row_count {
+            let values: Vec<bool> = (0..variables.len()).map(|bit| (i >> bit) & 1 == 1).collect();
+            let assignment: HashMap<String, bool> = variables
+                .iter()
+                .cloned()
+                .zip(values.iter().copied())
+                .collect();
+            let result = self.evaluate(&assignment)?;
+            rows.push((values, result));
+        }
+        Ok(TruthTable { variables, rows })
+    }
+
+    /// Returns `true` if this expression evaluates to `true` under every assignment.
+    ///
+    /// See [`Expression::truth_table`] for when this fails.
+    pub fn is_tautology(&self) -> Result<bool, EvalError> {
+        Ok(self.truth_table()?.rows.iter().all(|(_, result)| *result))
+    }
+
+    /// Returns `true` if this expression evaluates to `true` under at least one assignment.
+    ///
+    /// See [`Expression::truth_table`] for when this fails.
+    pub fn is_satisfiable(&self) -> Result<bool, EvalError> {
+        Ok(self.truth_table()?.rows.iter().any(|(_, result)| *result))
+    }
+
+    /// Returns `true` if this expression evaluates to `false` under every assignment.
+    ///
+    /// See [`Expression::truth_table`] for when this fails.
+    pub fn is_contradiction(&self) -> Result<bool, EvalError> {
+        Ok(!self.is_satisfiable()?)
+    }
+
+    /// Renders this expression with the minimal parentheses needed to preserve its
+    /// structure, equivalent to `format!("{:#}", self)`. Re-parsing the result with
+    /// [`crate::parser::Parser`] yields a structurally identical `Expression`.
+    pub fn to_pretty_string(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// Precedence of `Not`/`Var`/`Predicate`: higher than every binary operator, since
+    /// they are atomic and never need parentheses as someone else's child.
+    const ATOM_PRECEDENCE: u8 = 5;
+
+    /// Precedence of `ForAll`/`Exists`: lower than every binary operator, since a
+    /// quantifier's body extends as far right as it can and so must be parenthesized
+    /// whenever it appears as the operand of a binary connective.
+    const QUANTIFIER_PRECEDENCE: u8 = 0;
+
+    /// Binding precedence used by the pretty-printer, mirroring the parser's operator
+    /// ranking (higher binds tighter).
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Iff(..) => 1,
+            Expression::Implies(..) => 2,
+            Expression::Or(..) | Expression::Xor(..) => 3,
+            Expression::And(..) => 4,
+            Expression::Not(..) | Expression::Var(..) | Expression::Predicate(..) => {
+                Self::ATOM_PRECEDENCE
+            }
+            Expression::ForAll(..) | Expression::Exists(..) => Self::QUANTIFIER_PRECEDENCE,
+        }
+    }
+
+    /// Only IMPLIES is right-associative, matching the parser.
+    fn is_right_associative(&self) -> bool {
+        matches!(self, Expression::Implies(..))
+    }
+
+    /// Writes this expression, parenthesizing itself only if its precedence is lower
+    /// than `min_prec` (the precedence its parent requires of this position).
+    fn fmt_pretty(&self, f: &mut fmt::Formatter<'_>, min_prec: u8) -> fmt::Result {
+        match self {
+            Expression::Var(name) => write!(f, "{}", name),
+            Expression::Not(expr) => {
+                write!(f, "~")?;
+                expr.fmt_pretty(f, Self::ATOM_PRECEDENCE)
+            }
+            Expression::And(left, right)
+            | Expression::Or(left, right)
+            | Expression::Xor(left, right)
+            | Expression::Implies(left, right)
+            | Expression::Iff(left, right) => {
+                let prec = self.precedence();
+                let symbol = match self {
+                    Expression::And(..) => "&",
+                    Expression::Or(..) => "v",
+                    Expression::Xor(..) => "^",
+                    Expression::Implies(..) => "->",
+                    Expression::Iff(..) => "<->",
+                    _ => unreachable!(),
+                };
+                let (left_min, right_min) = if self.is_right_associative() {
+                    (prec + 1, prec)
+                } else {
+                    (prec, prec + 1)
+                };
+                let needs_parens = prec < min_prec;
+                if needs_parens {
+                    write!(f, "(")?;
+                }
+                left.fmt_pretty(f, left_min)?;
+                write!(f, " {} ", symbol)?;
+                right.fmt_pretty(f, right_min)?;
+                if needs_parens {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            Expression::ForAll(name, body) | Expression::Exists(name, body) => {
+                let symbol = if matches!(self, Expression::ForAll(..)) {
+                    "\u{2200}"
+                } else {
+                    "\u{2203}"
+                };
+                let needs_parens = self.precedence() < min_prec;
+                if needs_parens {
+                    write!(f, "(")?;
+                }
+                write!(f, "{}{}. ", symbol, name)?;
+                body.fmt_pretty(f, 0)?;
+                if needs_parens {
+                    write!(f, ")")?;
+                }
+                Ok(())
+            }
+            Expression::Predicate(name, args) => {
+                write!(f, "{}(", name)?;
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", arg)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+/// Error returned by [`Expression::evaluate`] when the expression cannot be reduced
+/// to a single boolean under `assignment`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// A variable appears in the expression that has no entry in `assignment`.
+    UndefinedVar(String),
+
+    /// The expression contains a quantifier or predicate, which has no propositional
+    /// truth value under a variable assignment alone.
+    NotPropositional,
+}
+
+impl Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::UndefinedVar(name) => write!(f, "undefined variable '{}'", name),
+            EvalError::NotPropositional => write!(
+                f,
+                "expression has no propositional truth value (quantifiers and predicates \
+                 require a first-order interpretation, not a variable assignment)"
+            ),
+        }
+    }
+}
+
+/// The full truth table of an [`Expression`]: its sorted variables, and one row per
+/// assignment (in binary counting order over those variables) pairing the assignment's
+/// values with the resulting truth value.
+pub struct TruthTable {
+    pub variables: Vec<String>,
+    pub rows: Vec<(Vec<bool>, bool)>,
 }
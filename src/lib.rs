@@ -0,0 +1,5 @@
+//! A small crate for building, parsing, and displaying propositional logic
+//! expressions.
+
+pub mod expression;
+pub mod parser;
@@ -0,0 +1,392 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::expression::{Expression, Term};
+
+/// An error produced while parsing, carrying the position at which the problem was
+/// found so callers can report exactly where a formula broke.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    input: String,
+    /// Char offset into the input where the error was detected.
+    pub position: usize,
+    /// The unexpected token found at `position`, or `None` at end of input.
+    pub found: Option<String>,
+    /// A short human-readable description of what went wrong.
+    pub message: String,
+}
+
+impl ParseError {
+    fn at(chars: &[char], position: usize, found: Option<char>, message: impl Into<String>) -> Self {
+        ParseError {
+            input: chars.iter().collect(),
+            position,
+            found: found.map(|c| c.to_string()),
+            message: message.into(),
+        }
+    }
+}
+
+/// Renders the error message followed by the input with a caret pointing at `position`.
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{}", self.input)?;
+        write!(f, "{}^", " ".repeat(self.position))
+    }
+}
+
+/// Parses propositional and first-order logic formulas into an [`Expression`] AST.
+///
+/// Propositional variables and predicate symbols are runs of uppercase letters and
+/// digits (`A`, `LOVES`) — restricted to uppercase so they can't absorb the lowercase
+/// `v` OR token or a following lowercase term variable. `&` is AND, `|` or `v` is OR,
+/// `->` is IMPLIES, `<->` or `=` is IFF, `^` is XOR, and `-` or `~` is NOT. Whitespace
+/// is ignored.
+///
+/// Binary operators are parsed by precedence climbing, so formulas no
+/// longer need full parenthesization: NOT binds tightest (as a prefix
+/// operator), then AND, then OR and XOR, then IMPLIES, then IFF, loosest.
+/// AND, OR, XOR, and IFF are left-associative; IMPLIES is right-associative,
+/// so `A->B->C` parses as `A->(B->C)`.
+///
+/// First-order formulas add `∀x. <body>` and `∃x. <body>` quantifiers (the bound
+/// variable is a lowercase identifier) and `Predicate(t1, t2, ...)` applications,
+/// whose arguments are [`Term`]s: lowercase identifiers are term variables, uppercase
+/// identifiers are named constants, and either followed immediately by `(...)` is a
+/// function application. A quantifier's body extends as far right as it can, so it
+/// must be parenthesized when used as the operand of a binary connective, e.g.
+/// `(∀x. P(x)) & Q`. The parser tracks which variable names are currently bound by an
+/// enclosing quantifier on a stack; term variables it encounters that are not on that
+/// stack are recorded as free — see [`Parser::free_variables`]. Propositional
+/// variables, constants, and function names are not recorded.
+pub struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    /// Stack of variable names currently bound by an enclosing `∀`/`∃`.
+    bound: Vec<String>,
+    /// Term variables encountered that were not bound by an enclosing quantifier.
+    free_variables: BTreeSet<String>,
+}
+
+impl Parser {
+    /// Creates a new `Parser` over the given input string.
+    pub fn new(input: &str) -> Self {
+        Parser {
+            chars: input.chars().collect(),
+            pos: 0,
+            bound: Vec::new(),
+            free_variables: BTreeSet::new(),
+        }
+    }
+
+    /// The term variables seen so far that were not bound by an enclosing quantifier.
+    pub fn free_variables(&self) -> &BTreeSet<String> {
+        &self.free_variables
+    }
+
+    /// Parses the full input into a [`ParsedFormula`], pairing the `Expression` with
+    /// the set of term variables that occur free in it.
+    pub fn parse_formula(&mut self) -> Result<ParsedFormula, ParseError> {
+        let expression = self.parse()?;
+        Ok(ParsedFormula {
+            expression,
+            free_variables: self.free_variables.clone(),
+        })
+    }
+
+    /// Parses the full input into an `Expression`, failing if any input is left unconsumed.
+    pub fn parse(&mut self) -> Result<Expression, ParseError> {
+        let expr = self.parse_expression(0)?;
+        self.skip_whitespace();
+        if self.pos != self.chars.len() {
+            return Err(self.error("unexpected trailing input"));
+        }
+        Ok(expr)
+    }
+
+    /// Builds a [`ParseError`] at the current position.
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(&self.chars, self.pos, self.peek(), message)
+    }
+
+    /// Parses a (possibly binary) expression, consuming only operators whose
+    /// precedence is at least `min_prec`. This is the climbing step of the
+    /// precedence-climbing algorithm: left-associative operators recurse
+    /// with `min_prec = op.precedence() + 1`, while the right-associative
+    /// IMPLIES recurses with `min_prec = op.precedence()`.
+    fn parse_expression(&mut self, min_prec: u8) -> Result<Expression, ParseError> {
+        let mut left = self.parse_primary()?;
+        loop {
+            self.skip_whitespace();
+            let saved_pos = self.pos;
+            match self.parse_binary_op() {
+                Some(op) if op.precedence() >= min_prec => {
+                    self.skip_whitespace();
+                    let next_min = if op.is_right_associative() {
+                        op.precedence()
+                    } else {
+                        op.precedence() + 1
+                    };
+                    let right = self.parse_expression(next_min)?;
+                    left = op.build(left, right);
+                }
+                Some(_) => {
+                    self.pos = saved_pos;
+                    break;
+                }
+                None => break,
+            }
+        }
+        Ok(left)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c.is_ascii_uppercase() => {
+                let name = self.parse_uppercase_identifier();
+                if self.peek() == Some('(') {
+                    let args = self.parse_term_list()?;
+                    Ok(Expression::Predicate(name, args))
+                } else {
+                    Ok(Expression::Var(name))
+                }
+            }
+            Some('-') | Some('~') => {
+                self.pos += 1;
+                let inner = self.parse_primary()?;
+                Ok(Expression::Not(Rc::new(inner)))
+            }
+            Some('\u{2200}') | Some('\u{2203}') => self.parse_quantifier(),
+            Some('(') => {
+                self.pos += 1;
+                let inner = self.parse_expression(0)?;
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(inner)
+                    }
+                    _ => Err(self.error("expected ')'")),
+                }
+            }
+            Some(c) => Err(self.error(format!("unexpected character '{}'", c))),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    /// Parses a `∀x. <body>` or `∃x. <body>` quantified formula. The bound variable
+    /// is pushed onto the binder stack before the body is parsed (so occurrences of
+    /// it inside the body are not recorded as free) and popped afterwards.
+    fn parse_quantifier(&mut self) -> Result<Expression, ParseError> {
+        let is_forall = self.peek() == Some('\u{2200}');
+        self.pos += 1;
+        self.skip_whitespace();
+        let name = match self.peek() {
+            Some(c) if c.is_ascii_lowercase() => self.parse_lowercase_identifier(),
+            _ => return Err(self.error("expected a lowercase bound variable name")),
+        };
+        self.skip_whitespace();
+        match self.peek() {
+            Some('.') => self.pos += 1,
+            _ => return Err(self.error("expected '.' after bound variable")),
+        }
+        self.skip_whitespace();
+        self.bound.push(name.clone());
+        let body = self.parse_expression(0);
+        self.bound.pop();
+        let body = Rc::new(body?);
+        Ok(if is_forall {
+            Expression::ForAll(name, body)
+        } else {
+            Expression::Exists(name, body)
+        })
+    }
+
+    /// Parses a parenthesized, comma-separated list of [`Term`]s, including the parens.
+    fn parse_term_list(&mut self) -> Result<Vec<Rc<Term>>, ParseError> {
+        self.pos += 1;
+        self.skip_whitespace();
+        let mut args = Vec::new();
+        if self.peek() == Some(')') {
+            self.pos += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(Rc::new(self.parse_term()?));
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => {
+                    self.pos += 1;
+                    self.skip_whitespace();
+                }
+                Some(')') => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => return Err(self.error("expected ',' or ')' in argument list")),
+            }
+        }
+        Ok(args)
+    }
+
+    /// Parses a single [`Term`]: a variable, a named constant, or a function
+    /// application. Term variables (lowercase identifiers not followed by `(`) not bound
+    /// by an enclosing quantifier are recorded in [`Parser::free_variables`].
+    fn parse_term(&mut self) -> Result<Term, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(c) if c.is_ascii_lowercase() => {
+                let name = self.parse_lowercase_identifier();
+                if self.peek() == Some('(') {
+                    let args = self.parse_term_list()?;
+                    Ok(Term::Func(name, args))
+                } else {
+                    if !self.bound.contains(&name) {
+                        self.free_variables.insert(name.clone());
+                    }
+                    Ok(Term::Var(name))
+                }
+            }
+            Some(c) if c.is_ascii_uppercase() => {
+                let name = self.parse_uppercase_identifier();
+                if self.peek() == Some('(') {
+                    let args = self.parse_term_list()?;
+                    Ok(Term::Func(name, args))
+                } else {
+                    Ok(Term::Const(name))
+                }
+            }
+            Some(c) => Err(self.error(format!("unexpected character '{}' in term", c))),
+            None => Err(self.error("unexpected end of input in term")),
+        }
+    }
+
+    /// Consumes a maximal run of uppercase letters/digits (variable and predicate
+    /// names). Restricted to uppercase so it can't absorb the lowercase `v` OR token
+    /// or a following lowercase term variable. Callers must have already checked that
+    /// the current character is an uppercase letter.
+    fn parse_uppercase_identifier(&mut self) -> String {
+        self.parse_identifier(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+    }
+
+    /// Consumes a maximal run of lowercase letters/digits (term variables, lowercase
+    /// function names, and quantifier-bound names). Callers must have already checked
+    /// that the current character is a lowercase letter.
+    fn parse_lowercase_identifier(&mut self) -> String {
+        self.parse_identifier(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    }
+
+    fn parse_identifier(&mut self, continues: impl Fn(char) -> bool) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if continues(c) {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    /// Consumes a binary operator at the current position, if there is one.
+    fn parse_binary_op(&mut self) -> Option<BinOp> {
+        match self.peek() {
+            Some('&') => {
+                self.pos += 1;
+                Some(BinOp::And)
+            }
+            Some('|') => {
+                self.pos += 1;
+                Some(BinOp::Or)
+            }
+            Some('v') => {
+                self.pos += 1;
+                Some(BinOp::Or)
+            }
+            Some('^') => {
+                self.pos += 1;
+                Some(BinOp::Xor)
+            }
+            Some('=') => {
+                self.pos += 1;
+                Some(BinOp::Iff)
+            }
+            Some('-') if self.peek_at(1) == Some('>') => {
+                self.pos += 2;
+                Some(BinOp::Implies)
+            }
+            Some('<') if self.peek_at(1) == Some('-') && self.peek_at(2) == Some('>') => {
+                self.pos += 3;
+                Some(BinOp::Iff)
+            }
+            _ => None,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+/// The result of [`Parser::parse_formula`]: a parsed expression together with the set
+/// of term variables that occur free in it (not bound by an enclosing quantifier).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedFormula {
+    pub expression: Expression,
+    pub free_variables: BTreeSet<String>,
+}
+
+/// The binary connectives recognized by the parser.
+enum BinOp {
+    And,
+    Or,
+    Implies,
+    Iff,
+    Xor,
+}
+
+impl BinOp {
+    /// Binding strength, highest first: AND, then OR/XOR, then IMPLIES, then IFF.
+    fn precedence(&self) -> u8 {
+        match self {
+            BinOp::And => 4,
+            BinOp::Or | BinOp::Xor => 3,
+            BinOp::Implies => 2,
+            BinOp::Iff => 1,
+        }
+    }
+
+    /// Only IMPLIES is right-associative (`A->B->C` parses as `A->(B->C)`).
+    fn is_right_associative(&self) -> bool {
+        matches!(self, BinOp::Implies)
+    }
+
+    fn build(self, left: Expression, right: Expression) -> Expression {
+        let left = Rc::new(left);
+        let right = Rc::new(right);
+        match self {
+            BinOp::And => Expression::And(left, right),
+            BinOp::Or => Expression::Or(left, right),
+            BinOp::Implies => Expression::Implies(left, right),
+            BinOp::Iff => Expression::Iff(left, right),
+            BinOp::Xor => Expression::Xor(left, right),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use propositional_logic_calculator::expression::{not, predicate, var};
+use std::collections::HashMap;
+
+fn assignment(pairs: &[(&str, bool)]) -> HashMap<String, bool> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), *value))
+        .collect()
+}
+
+#[test]
+fn test_evaluate_and() {
+    let expr = var("A").and(&var("B"));
+    assert_eq!(
+        expr.evaluate(&assignment(&[("A", true), ("B", true)]))
+            .unwrap(),
+        true
+    );
+    assert_eq!(
+        expr.evaluate(&assignment(&[("A", true), ("B", false)]))
+            .unwrap(),
+        false
+    );
+}
+
+#[test]
+fn test_evaluate_undefined_variable() {
+    let expr = var("A").and(&var("B"));
+    assert!(expr.evaluate(&assignment(&[("A", true)])).is_err());
+}
+
+#[test]
+fn test_variables_are_sorted_and_deduped() {
+    let expr = var("B").and(&var("A")).or(&var("B"));
+    assert_eq!(
+        expr.variables().into_iter().collect::<Vec<_>>(),
+        vec!["A".to_string(), "B".to_string()]
+    );
+}
+
+#[test]
+fn test_truth_table_has_a_row_per_assignment() {
+    let expr = var("A").and(&var("B"));
+    let table = expr.truth_table().unwrap();
+    assert_eq!(table.variables, vec!["A".to_string(), "B".to_string()]);
+    assert_eq!(table.rows.len(), 4);
+    assert_eq!(
+        table.rows.iter().filter(|(_, result)| *result).count(),
+        1
+    );
+}
+
+#[test]
+fn test_is_tautology() {
+    let a = var("A");
+    let expr = a.or(&not(&a));
+    assert!(expr.is_tautology().unwrap());
+}
+
+#[test]
+fn test_is_contradiction() {
+    let a = var("A");
+    let expr = a.and(&not(&a));
+    assert!(expr.is_contradiction().unwrap());
+    assert!(!expr.is_satisfiable().unwrap());
+}
+
+#[test]
+fn test_is_satisfiable_but_not_tautology() {
+    let expr = var("A").and(&var("B"));
+    assert!(expr.is_satisfiable().unwrap());
+    assert!(!expr.is_tautology().unwrap());
+}
+
+#[test]
+fn test_truth_table_on_predicate_is_not_propositional() {
+    let expr = predicate("P", vec![]);
+    assert!(expr.truth_table().is_err());
+    assert!(expr.is_satisfiable().is_err());
+    assert!(expr.is_tautology().is_err());
+}
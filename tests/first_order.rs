@@ -0,0 +1,113 @@
+use propositional_logic_calculator::expression::{exists, forall, func, predicate, term_const, term_var, var, Expression};
+use propositional_logic_calculator::parser::Parser;
+
+#[test]
+fn test_parse_predicate_with_no_args() {
+    let mut parser = Parser::new("P()");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *predicate("P", vec![])
+    );
+}
+
+#[test]
+fn test_parse_predicate_with_variable_argument() {
+    let mut parser = Parser::new("LOVES(x, y)");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *predicate("LOVES", vec![term_var("x"), term_var("y")])
+    );
+}
+
+#[test]
+fn test_parse_predicate_with_constant_and_function_arguments() {
+    let mut parser = Parser::new("P(A, f(x))");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *predicate("P", vec![term_const("A"), func("f", vec![term_var("x")])])
+    );
+}
+
+#[test]
+fn test_parse_forall_quantifier() {
+    let mut parser = Parser::new("\u{2200}x. P(x)");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *forall("x", &predicate("P", vec![term_var("x")]))
+    );
+}
+
+#[test]
+fn test_parse_exists_quantifier() {
+    let mut parser = Parser::new("\u{2203}x. P(x)");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *exists("x", &predicate("P", vec![term_var("x")]))
+    );
+}
+
+#[test]
+fn test_quantifier_body_extends_as_far_right_as_possible() {
+    let mut parser = Parser::new("\u{2200}x. P(x) & Q(x)");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *forall(
+            "x",
+            &predicate("P", vec![term_var("x")]).and(&predicate("Q", vec![term_var("x")]))
+        )
+    );
+}
+
+#[test]
+fn test_quantifier_must_be_parenthesized_as_binary_operand() {
+    let mut parser = Parser::new("(\u{2200}x. P(x)) & Q");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *forall("x", &predicate("P", vec![term_var("x")])).and(&var("Q"))
+    );
+}
+
+#[test]
+fn test_free_variables_excludes_bound_variable() {
+    let mut parser = Parser::new("\u{2200}x. P(x, y)");
+    let parsed = parser.parse_formula().unwrap();
+    assert_eq!(
+        parsed.free_variables.into_iter().collect::<Vec<_>>(),
+        vec!["y".to_string()]
+    );
+}
+
+#[test]
+fn test_free_variables_sees_variable_free_outside_its_quantifier() {
+    let mut parser = Parser::new("(\u{2200}x. P(x)) & Q(x)");
+    let parsed = parser.parse_formula().unwrap();
+    assert_eq!(
+        parsed.free_variables.into_iter().collect::<Vec<_>>(),
+        vec!["x".to_string()]
+    );
+}
+
+#[test]
+fn test_list_expressions_descends_into_quantifier_body() {
+    let expr = forall("x", &predicate("P", vec![term_var("x")]));
+    let expressions = expr.list_expressions();
+    assert_eq!(expressions.len(), 2);
+}
+
+#[test]
+fn test_display_of_quantified_formula() {
+    let expr = forall("x", &predicate("P", vec![term_var("x")]));
+    assert_eq!(expr.to_string(), "\u{2200}x. P(x)");
+}
+
+#[test]
+fn test_pretty_print_parenthesizes_quantifier_as_binary_operand() {
+    let expr = forall("x", &predicate("P", vec![term_var("x")])).and(&var("Q"));
+    assert_eq!(expr.to_pretty_string(), "(\u{2200}x. P(x)) & Q");
+}
+
+#[test]
+fn test_evaluate_quantified_formula_is_undefined() {
+    let expr: Expression = (*forall("x", &predicate("P", vec![term_var("x")]))).clone();
+    assert!(expr.evaluate(&Default::default()).is_err());
+}
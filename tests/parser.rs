@@ -14,7 +14,7 @@ fn test_parse_and_expression() {
     let mut parser = Parser::new("A&B");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").and(var("B"))
+        *var("A").and(&var("B"))
     );
 }
 
@@ -23,21 +23,59 @@ fn test_parse_or_expression() {
     let mut parser = Parser::new("A|B");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").or(var("B"))
+        *var("A").or(&var("B"))
     );
     let mut parser = Parser::new("AvB");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").or(var("B"))
+        *var("A").or(&var("B"))
     );
 }
 
+#[test]
+fn test_parse_iff_expression() {
+    let mut parser = Parser::new("A<->B");
+    assert_eq!(parser.parse().unwrap(), *var("A").iff(&var("B")));
+    let mut parser = Parser::new("A=B");
+    assert_eq!(parser.parse().unwrap(), *var("A").iff(&var("B")));
+}
+
+#[test]
+fn test_parse_xor_expression() {
+    let mut parser = Parser::new("A^B");
+    assert_eq!(parser.parse().unwrap(), *var("A").xor(&var("B")));
+}
+
+#[test]
+fn test_parse_without_parens_respects_precedence() {
+    let mut parser = Parser::new("A&B|C");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *var("A").and(&var("B")).or(&var("C"))
+    );
+}
+
+#[test]
+fn test_parse_implies_is_right_associative() {
+    let mut parser = Parser::new("A->B->C");
+    assert_eq!(
+        parser.parse().unwrap(),
+        *var("A").implies(&var("B").implies(&var("C")))
+    );
+}
+
+#[test]
+fn test_parse_not_without_parens() {
+    let mut parser = Parser::new("-A|B");
+    assert_eq!(parser.parse().unwrap(), *not(&var("A")).or(&var("B")));
+}
+
 #[test]
 fn test_parse_not_expression() {
     let mut parser = Parser::new("-A");
     assert_eq!(
         parser.parse().unwrap(),
-        *not(var("A"))
+        *not(&var("A"))
     );
 }
 
@@ -46,20 +84,24 @@ fn test_parse_nested_expression() {
     let mut parser = Parser::new("-(A&B)");
     assert_eq!(
         parser.parse().unwrap(),
-        *not(var("A").and(var("B")))
+        *not(&var("A").and(&var("B")))
     );
 }
 
 #[test]
 fn test_parse_with_unmatched_parentheses() {
     let mut parser = Parser::new("A&");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 2);
+    assert_eq!(err.found, None);
 }
 
 #[test]
 fn test_parse_with_invalid_character() {
     let mut parser = Parser::new("A$B");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 1);
+    assert_eq!(err.found, Some("$".to_string()));
 }
 
 #[test]
@@ -67,7 +109,7 @@ fn test_ingore_invalid_characters() {
     let mut parser = Parser::new("A & B");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").and(var("B"))
+        *var("A").and(&var("B"))
     );
 }
 
@@ -76,7 +118,7 @@ fn test_ignore_invalid_character_in_brackets() {
     let mut parser = Parser::new("(A & B)");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").and(var("B"))
+        *var("A").and(&var("B"))
     );
 }
 
@@ -85,36 +127,55 @@ fn test_deeply_nested_expression() {
     let mut parser = Parser::new("(((((A))))&B)");
     assert_eq!(
         parser.parse().unwrap(),
-        *var("A").and(var("B"))
+        *var("A").and(&var("B"))
     );
 }
 
 #[test]
 fn test_invalid_nesting() {
     let mut parser = Parser::new("(A&B))|(C");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 5);
+    assert_eq!(err.found, Some(")".to_string()));
 }
 
 #[test]
 fn test_empty_input() {
     let mut parser = Parser::new("");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 0);
+    assert_eq!(err.found, None);
 }
 
 #[test]
 fn test_repeated_operators() {
     let mut parser = Parser::new("A&&B");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 2);
+    assert_eq!(err.found, Some("&".to_string()));
 }
 
 #[test]
 fn test_only_operators() {
     let mut parser = Parser::new("&|>");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 0);
+    assert_eq!(err.found, Some("&".to_string()));
 }
 
 #[test]
 fn test_invalid_characters() {
     let mut parser = Parser::new("A&B#C");
-    assert!(parser.parse().is_err());
+    let err = parser.parse().unwrap_err();
+    assert_eq!(err.position, 3);
+    assert_eq!(err.found, Some("#".to_string()));
+}
+
+#[test]
+fn test_parse_error_display_has_caret() {
+    let mut parser = Parser::new("A&B#C");
+    let err = parser.parse().unwrap_err();
+    let rendered = err.to_string();
+    assert!(rendered.contains("A&B#C"));
+    assert!(rendered.ends_with(&format!("{}^", " ".repeat(3))));
 }
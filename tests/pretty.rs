@@ -0,0 +1,63 @@
+use propositional_logic_calculator::expression::var;
+use propositional_logic_calculator::parser::Parser;
+
+fn assert_round_trips(input: &str) {
+    let original = Parser::new(input).parse().unwrap();
+    let pretty = original.to_pretty_string();
+    let reparsed = Parser::new(&pretty).parse().unwrap();
+    assert_eq!(original, reparsed, "{:?} did not round-trip through {:?}", input, pretty);
+}
+
+#[test]
+fn test_pretty_print_omits_redundant_parens() {
+    let expr = var("A").and(&var("B")).and(&var("C"));
+    assert_eq!(expr.to_pretty_string(), "A & B & C");
+}
+
+#[test]
+fn test_pretty_print_keeps_parens_for_mixed_precedence() {
+    let expr = var("A").and(&var("B").or(&var("C")));
+    assert_eq!(expr.to_pretty_string(), "A & (B v C)");
+}
+
+#[test]
+fn test_pretty_print_keeps_parens_for_right_heavy_and() {
+    let expr = var("A").and(&var("B").and(&var("C")));
+    assert_eq!(expr.to_pretty_string(), "A & (B & C)");
+}
+
+#[test]
+fn test_pretty_print_implies_chain_is_right_associative() {
+    let expr = var("A").implies(&var("B").implies(&var("C")));
+    assert_eq!(expr.to_pretty_string(), "A -> B -> C");
+}
+
+#[test]
+fn test_pretty_print_implies_left_heavy_keeps_parens() {
+    let expr = var("A").implies(&var("B")).implies(&var("C"));
+    assert_eq!(expr.to_pretty_string(), "(A -> B) -> C");
+}
+
+#[test]
+fn test_pretty_print_not_of_binary_keeps_parens() {
+    let expr = var("A").and(&var("B"));
+    let not_expr = propositional_logic_calculator::expression::not(&expr);
+    assert_eq!(not_expr.to_pretty_string(), "~(A & B)");
+}
+
+#[test]
+fn test_round_trip_through_pretty_printer() {
+    for input in [
+        "A",
+        "A&B",
+        "A&B&C",
+        "A&(B v C)",
+        "A->B->C",
+        "(A->B)->C",
+        "A<->B^C",
+        "~(A&B)v C",
+        "~~A",
+    ] {
+        assert_round_trips(input);
+    }
+}